@@ -0,0 +1,42 @@
+//! Small Gregorian calendar helpers shared by the occurrence scheduler and the
+//! calendar-aware `day`/`day_of_week` field extensions (`L`, `W`, `#`).
+
+pub(crate) fn is_leap_year(year: u16) -> bool {
+    (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400)
+}
+
+pub(crate) fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 30,
+    }
+}
+
+/// Sakamoto's algorithm, returning Sunday = 0 to match this crate's `day_of_week` convention.
+pub(crate) fn day_of_week(year: u16, month: u8, day: u8) -> u8 {
+    const T: [i32; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+
+    let mut y = year as i32;
+    if month < 3 {
+        y -= 1;
+    }
+
+    let w = (y + y / 4 - y / 100 + y / 400 + T[month as usize - 1] + day as i32) % 7;
+    w as u8
+}
+
+/// The weekday (Mon-Fri) nearest `target` within the same month, per the Quartz `W` rule: a
+/// `target` that falls on a weekend moves to the closest weekday without crossing into the
+/// next or previous month.
+pub(crate) fn nearest_weekday(year: u16, month: u8, target: u8) -> u8 {
+    match day_of_week(year, month, target) {
+        0 if target < days_in_month(year, month) => target + 1, // Sunday -> following Monday
+        0 => target - 2,                                        // ...unless that's next month
+        6 if target > 1 => target - 1,                          // Saturday -> preceding Friday
+        6 => target + 2,                                        // ...unless that's last month
+        _ => target,
+    }
+}
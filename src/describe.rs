@@ -0,0 +1,304 @@
+//! Rendering a [`CronExpr`] as an English sentence, for UIs that don't want to show users a raw
+//! cron string.
+
+use std::{
+    fmt::{self, Display},
+    ops::RangeInclusive,
+};
+
+use crate::{CronExpr, Field, FieldKind, ListValue, StepValue};
+
+impl CronExpr {
+    /// Renders this expression as a deterministic, English-only sentence, e.g.
+    /// `"at second 30, every 5 minutes from 0 through 30, during hours 13 through 15 and 18,
+    /// Monday through Friday"`. Fully unrestricted `day`/`month`/`day_of_week` fields
+    /// contribute nothing to the sentence.
+    pub fn describe(&self) -> String {
+        self.description().to_string()
+    }
+
+    /// The [`Description`] of this expression, for callers that want to defer rendering or
+    /// tweak the wording downstream.
+    pub fn description(&self) -> Description<'_> {
+        Description(self)
+    }
+}
+
+/// A [`Display`]-able English description of a [`CronExpr`]. Build one with
+/// [`CronExpr::description`].
+pub struct Description<'a>(&'a CronExpr);
+
+impl Display for Description<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parts: Vec<String> = self
+            .0
+            .fields()
+            .filter_map(|(kind, field)| describe_field(kind, field))
+            .collect();
+
+        f.write_str(&parts.join(", "))
+    }
+}
+
+const MONTH_NAMES: [&str; 13] = [
+    "", "January", "February", "March", "April", "May", "June", "July", "August", "September",
+    "October", "November", "December",
+];
+
+const DAY_NAMES: [&str; 7] = [
+    "Sunday", "Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday",
+];
+
+/// The vocabulary needed to render one field kind: what to call a single value versus several
+/// of them, the plural noun used after "every N", the field's valid domain, and (for
+/// `month`/`day_of_week`) the names to substitute for the raw numbers.
+struct Words {
+    singular_prefix: &'static str,
+    plural_prefix: &'static str,
+    plural_noun: &'static str,
+    bounds: RangeInclusive<u8>,
+    names: Option<&'static [&'static str]>,
+}
+
+fn words_for(kind: FieldKind) -> Words {
+    let bounds = kind.bounds();
+
+    match kind {
+        FieldKind::Second => Words {
+            singular_prefix: "at second ",
+            plural_prefix: "at seconds ",
+            plural_noun: "seconds",
+            bounds,
+            names: None,
+        },
+        FieldKind::Minute => Words {
+            singular_prefix: "at minute ",
+            plural_prefix: "at minutes ",
+            plural_noun: "minutes",
+            bounds,
+            names: None,
+        },
+        FieldKind::Hour => Words {
+            singular_prefix: "during hour ",
+            plural_prefix: "during hours ",
+            plural_noun: "hours",
+            bounds,
+            names: None,
+        },
+        FieldKind::Day => Words {
+            singular_prefix: "on day ",
+            plural_prefix: "on days ",
+            plural_noun: "days",
+            bounds,
+            names: None,
+        },
+        FieldKind::Month => Words {
+            singular_prefix: "in ",
+            plural_prefix: "in ",
+            plural_noun: "months",
+            bounds,
+            names: Some(&MONTH_NAMES),
+        },
+        FieldKind::DayOfWeek => Words {
+            singular_prefix: "",
+            plural_prefix: "",
+            plural_noun: "weekdays",
+            bounds,
+            names: Some(&DAY_NAMES),
+        },
+    }
+}
+
+/// Renders `value`, substituting a name from `words.names` when one is configured. Falls back
+/// to the raw number if `value` is outside `words.bounds` -- fields can be set directly via
+/// [`CronExpr::set_field`] without going through [`CronExpr::parse`], so this can't assume
+/// `value` is in range. Checking `bounds` rather than just `names.get(..)` matters for
+/// `MONTH_NAMES`, which has a real (empty-string) entry at index 0 to align 1-based months with
+/// the array -- a plain `Vec::get` bounds check would let `0` through as a blank name instead of
+/// falling back.
+fn render_value(words: &Words, value: u8) -> String {
+    match words.names {
+        Some(names) if words.bounds.contains(&value) => names
+            .get(value as usize)
+            .map(|name| name.to_string())
+            .unwrap_or_else(|| value.to_string()),
+        _ => value.to_string(),
+    }
+}
+
+/// Renders a weekday number, falling back to the raw number when out of range. Shared by the
+/// `NthDayOfWeek`/`LastDayOfWeek` arms, which look up `DAY_NAMES` directly rather than through
+/// [`Words`] since they can occur outside the `day_of_week` field's own [`FieldKind`].
+fn weekday_name(dow: u8) -> String {
+    DAY_NAMES
+        .get(dow as usize)
+        .map(|name| name.to_string())
+        .unwrap_or_else(|| dow.to_string())
+}
+
+fn render_list_value(words: &Words, value: &ListValue) -> String {
+    match value {
+        ListValue::Value(v) => render_value(words, *v),
+        ListValue::Range(r) => format!(
+            "{} through {}",
+            render_value(words, *r.start()),
+            render_value(words, *r.end())
+        ),
+    }
+}
+
+/// Joins already-rendered items as English prose: `"a"`, `"a and b"`, `"a, b and c"`.
+fn join_prose(items: Vec<String>) -> String {
+    match items.split_last() {
+        Some((last, rest)) if !rest.is_empty() => format!("{} and {}", rest.join(", "), last),
+        Some((last, _)) => last.clone(),
+        None => String::new(),
+    }
+}
+
+fn ordinal(n: u8) -> &'static str {
+    match n {
+        1 => "1st",
+        2 => "2nd",
+        3 => "3rd",
+        4 => "4th",
+        5 => "5th",
+        _ => "nth",
+    }
+}
+
+/// Describes one field, or returns `None` when it contributes nothing to the sentence (a fully
+/// unrestricted `day`, `month`, or `day_of_week`).
+fn describe_field(kind: FieldKind, field: &Field) -> Option<String> {
+    match (kind, field) {
+        (FieldKind::Day | FieldKind::Month | FieldKind::DayOfWeek, Field::Any) => None,
+        (FieldKind::Second, Field::Any) => Some("every second".to_string()),
+        (FieldKind::Minute, Field::Any) => Some("every minute".to_string()),
+        (FieldKind::Hour, Field::Any) => Some("every hour".to_string()),
+        _ => Some(describe_constrained(kind, field)),
+    }
+}
+
+fn describe_constrained(kind: FieldKind, field: &Field) -> String {
+    match field {
+        Field::LastDayOfMonth => return "the last day of the month".to_string(),
+        Field::NearestWeekday(day) => return format!("the weekday nearest day {day}"),
+        Field::NthDayOfWeek { dow, nth } => {
+            return format!("the {} {} of the month", ordinal(*nth), weekday_name(*dow))
+        }
+        Field::LastDayOfWeek(dow) => {
+            return format!("the last {} of the month", weekday_name(*dow))
+        }
+        _ => {}
+    }
+
+    let words = words_for(kind);
+
+    match field {
+        Field::Any => unreachable!("Field::Any is handled by describe_field"),
+        Field::Value(v) => format!("{}{}", words.singular_prefix, render_value(&words, *v)),
+        Field::Range(r) => format!(
+            "{}{} through {}",
+            words.plural_prefix,
+            render_value(&words, *r.start()),
+            render_value(&words, *r.end())
+        ),
+        Field::List(l) => format!(
+            "{}{}",
+            words.plural_prefix,
+            join_prose(l.iter().map(|v| render_list_value(&words, v)).collect())
+        ),
+        Field::Step(StepValue::All, s) => format!("every {} {}", s, words.plural_noun),
+        Field::Step(StepValue::Range(r), s) => format!(
+            "every {} {} from {} through {}",
+            s,
+            words.plural_noun,
+            render_value(&words, *r.start()),
+            render_value(&words, *r.end())
+        ),
+        Field::NthDayOfWeek { .. }
+        | Field::LastDayOfWeek(_)
+        | Field::LastDayOfMonth
+        | Field::NearestWeekday(_) => unreachable!("handled above"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CronExpr, Field, FieldKind};
+
+    #[test]
+    fn describe_matches_the_readme_example() {
+        let expr = CronExpr::parse("30 0-30/5 13-15,18 * * 1-5").unwrap();
+        assert_eq!(
+            "at second 30, every 5 minutes from 0 through 30, during hours 13 through 15 and 18, Monday through Friday",
+            expr.describe()
+        );
+    }
+
+    #[test]
+    fn describe_collapses_any_fields() {
+        assert_eq!("every second, every minute, every hour", CronExpr::default().describe());
+    }
+
+    #[test]
+    fn describe_names_months_and_weekdays() {
+        let expr = CronExpr::parse("0 0 0 * jan,mar,DEC MON-FRI").unwrap();
+        assert_eq!(
+            "at second 0, at minute 0, during hour 0, in January, March and December, Monday through Friday",
+            expr.describe()
+        );
+    }
+
+    #[test]
+    fn describe_quartz_extensions() {
+        assert_eq!(
+            "every second, every minute, every hour, the last day of the month",
+            CronExpr::parse("* * * L * *").unwrap().describe()
+        );
+        assert_eq!(
+            "every second, every minute, every hour, the weekday nearest day 15",
+            CronExpr::parse("* * * 15W * *").unwrap().describe()
+        );
+        assert_eq!(
+            "every second, every minute, every hour, the 2nd Friday of the month",
+            CronExpr::parse("* * * * * 5#2").unwrap().describe()
+        );
+        assert_eq!(
+            "every second, every minute, every hour, the last Friday of the month",
+            CronExpr::parse("* * * * * 5L").unwrap().describe()
+        );
+    }
+
+    #[test]
+    fn describe_falls_back_to_numbers_for_out_of_range_values_set_directly() {
+        // `set_field` bypasses `parse`'s validation, so `describe` can't assume `month` and
+        // `day_of_week` values are in range.
+        let mut expr = CronExpr::default();
+        expr.set_field(FieldKind::Month, Field::Value(200));
+        assert_eq!("every second, every minute, every hour, in 200", expr.describe());
+
+        let mut expr = CronExpr::default();
+        expr.set_field(FieldKind::DayOfWeek, Field::LastDayOfWeek(200));
+        assert_eq!(
+            "every second, every minute, every hour, the last 200 of the month",
+            expr.describe()
+        );
+    }
+
+    #[test]
+    fn describe_falls_back_to_number_for_month_zero_set_directly() {
+        // `MONTH_NAMES` has a real (empty-string) entry at index 0 to align 1-based months
+        // with the array, so a plain `Vec::get` bounds check would let this through as a
+        // blank name instead of falling back to the number.
+        let mut expr = CronExpr::default();
+        expr.set_field(FieldKind::Month, Field::Value(0));
+        assert_eq!("every second, every minute, every hour, in 0", expr.describe());
+    }
+
+    #[test]
+    fn describe_is_deterministic() {
+        let expr = CronExpr::parse("30 0-30/5 13-15,18 * * 1-5").unwrap();
+        assert_eq!(expr.describe(), expr.describe());
+    }
+}
@@ -1,5 +1,7 @@
 use std::ops::RangeInclusive;
 
+use crate::calendar::{day_of_week, days_in_month, nearest_weekday};
+
 #[derive(Debug, Default, PartialEq, Eq, Clone)]
 pub struct CronExpr {
     pub second: Field,
@@ -17,35 +19,83 @@ impl CronExpr {
         self.second.check(&dt.second)
             && self.minute.check(&dt.minute)
             && self.hour.check(&dt.hour)
-            && self.day.check(&dt.day)
-            && self.month.check(&dt.month.into())
-            && self.day_of_week.check(&dt.day_of_week.into())
+            && self.day_matches(dt.year, dt.month, dt.day)
+            && self.month.check(&dt.month)
+    }
+
+    /// A day matches if the `day` field matches, OR the `day_of_week` field matches,
+    /// unless one of the two is `Any`, per standard cron semantics.
+    pub(crate) fn day_matches(&self, year: u16, month: u8, day: u8) -> bool {
+        match (&self.day, &self.day_of_week) {
+            (Field::Any, Field::Any) => true,
+            (Field::Any, _) => self.day_of_week.check_dow(year, month, day),
+            (_, Field::Any) => self.day.check_day(year, month, day),
+            _ => {
+                self.day.check_day(year, month, day) || self.day_of_week.check_dow(year, month, day)
+            }
+        }
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
 pub enum Field {
+    #[default]
     Any,
     Value(u8),
     Range(RangeInclusive<u8>),
     List(Vec<ListValue>),
     Step(StepValue, u8),
-}
-
-impl Default for Field {
-    fn default() -> Self {
-        Self::Any
-    }
+    /// `day_of_week` only: the `nth` occurrence of weekday `dow` in the month (Quartz `#`).
+    NthDayOfWeek { dow: u8, nth: u8 },
+    /// `day_of_week` only: the last occurrence of weekday `dow` in the month (Quartz `L`).
+    LastDayOfWeek(u8),
+    /// `day` only: the last day of the month (Quartz `L`).
+    LastDayOfMonth,
+    /// `day` only: the weekday nearest the given day of month (Quartz `W`).
+    NearestWeekday(u8),
 }
 
 impl Field {
-    fn check(&self, value: &u8) -> bool {
+    pub(crate) fn check(&self, value: &u8) -> bool {
         match self {
             Field::Any => true,
             Field::Value(v) => v == value,
             Field::Range(r) => r.contains(value),
             Field::List(l) => l.iter().any(|v| v.check(value)),
             Field::Step(r, s) => r.check(s, value),
+            Field::NthDayOfWeek { .. }
+            | Field::LastDayOfWeek(_)
+            | Field::LastDayOfMonth
+            | Field::NearestWeekday(_) => false,
+        }
+    }
+
+    /// Expands this field into the sorted list of values it allows within `domain`.
+    pub(crate) fn expand(&self, domain: RangeInclusive<u8>) -> Vec<u8> {
+        domain.filter(|v| self.check(v)).collect()
+    }
+
+    /// Checks this field as a `day` predicate, with the calendar context (`L`/`W` need the
+    /// month's length) needed to evaluate it.
+    pub(crate) fn check_day(&self, year: u16, month: u8, day: u8) -> bool {
+        match self {
+            Field::LastDayOfMonth => day == days_in_month(year, month),
+            Field::NearestWeekday(target) => day == nearest_weekday(year, month, *target),
+            Field::NthDayOfWeek { .. } | Field::LastDayOfWeek(_) => false,
+            _ => self.check(&day),
+        }
+    }
+
+    /// Checks this field as a `day_of_week` predicate, with the calendar context (`L`/`#`
+    /// need to know which occurrence of the weekday `day` is) needed to evaluate it.
+    pub(crate) fn check_dow(&self, year: u16, month: u8, day: u8) -> bool {
+        let dow = day_of_week(year, month, day);
+
+        match self {
+            Field::NthDayOfWeek { dow: d, nth } => *d == dow && (day - 1) / 7 + 1 == *nth,
+            Field::LastDayOfWeek(d) => *d == dow && day + 7 > days_in_month(year, month),
+            Field::LastDayOfMonth | Field::NearestWeekday(_) => false,
+            _ => self.check(&dow),
         }
     }
 }
@@ -74,20 +124,22 @@ pub enum StepValue {
 impl StepValue {
     fn check(&self, step: &u8, value: &u8) -> bool {
         match self {
-            StepValue::All => value % step == 0,
+            StepValue::All => value.is_multiple_of(*step),
             StepValue::Range(r) => {
-                if !r.contains(&value) {
+                if !r.contains(value) {
                     return false;
                 }
 
                 // start + step * n = value
-                (value - r.start()) % step == 0
+                (value - r.start()).is_multiple_of(*step)
             }
         }
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DateTime {
+    pub year: u16,
     pub second: u8,
     pub minute: u8,
     pub hour: u8,
@@ -105,10 +157,11 @@ pub mod time_rs_conversion {
     impl Into<DateTime> for OffsetDateTime {
         fn into(self) -> DateTime {
             DateTime {
+                year: self.year() as u16,
                 second: self.second(),
                 minute: self.minute(),
                 hour: self.hour(),
-                day: self.hour(),
+                day: self.day(),
                 month: self.month() as u8,
                 day_of_week: self.weekday().number_days_from_sunday(),
             }
@@ -116,6 +169,42 @@ pub mod time_rs_conversion {
     }
 }
 
+/// Mirrors [`time_rs_conversion`] for the `chrono` ecosystem.
+#[cfg(feature = "chrono")]
+pub mod chrono_conversion {
+    use ::chrono::{Datelike, TimeZone, Timelike};
+
+    use crate::DateTime;
+
+    impl<Tz: TimeZone> Into<DateTime> for ::chrono::DateTime<Tz> {
+        fn into(self) -> DateTime {
+            DateTime {
+                year: self.year() as u16,
+                second: self.second() as u8,
+                minute: self.minute() as u8,
+                hour: self.hour() as u8,
+                day: self.day() as u8,
+                month: self.month() as u8,
+                day_of_week: self.weekday().num_days_from_sunday() as u8,
+            }
+        }
+    }
+
+    impl Into<DateTime> for ::chrono::NaiveDateTime {
+        fn into(self) -> DateTime {
+            DateTime {
+                year: self.year() as u16,
+                second: self.second() as u8,
+                minute: self.minute() as u8,
+                hour: self.hour() as u8,
+                day: self.day() as u8,
+                month: self.month() as u8,
+                day_of_week: self.weekday().num_days_from_sunday() as u8,
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{tests::datetime, CronExpr, Field};
@@ -141,4 +230,35 @@ mod tests {
     fn clone() {
         CronExpr::default().clone();
     }
+
+    #[test]
+    fn last_day_of_month() {
+        assert!(Field::LastDayOfMonth.check_day(2024, 2, 29));
+        assert!(!Field::LastDayOfMonth.check_day(2023, 2, 29));
+    }
+
+    #[test]
+    fn nth_day_of_week() {
+        // 2024-3-1 is a Friday; the second Friday of March 2024 is the 8th.
+        let second_friday = Field::NthDayOfWeek { dow: 5, nth: 2 };
+        assert!(!second_friday.check_dow(2024, 3, 1));
+        assert!(second_friday.check_dow(2024, 3, 8));
+    }
+
+    #[test]
+    fn nearest_weekday() {
+        // 2024-3-2 is a Saturday; the nearest weekday is the preceding Friday.
+        assert!(Field::NearestWeekday(2).check_day(2024, 3, 1));
+        assert!(!Field::NearestWeekday(2).check_day(2024, 3, 2));
+    }
+
+    #[test]
+    fn check_time_matches_day_and_day_of_week_with_or_semantics() {
+        // 2024-3-8 is a Friday, not the 13th: `day_matches` must OR the two fields so
+        // `check_time` agrees with what the scheduler in `schedule.rs` yields.
+        let expr = CronExpr::parse("0 0 0 13 * 5").unwrap();
+        assert!(expr.check_time(datetime!(2024-3-8 0:0:0 5)));
+        assert!(expr.check_time(datetime!(2024-3-13 0:0:0 3)));
+        assert!(!expr.check_time(datetime!(2024-3-2 0:0:0 6)));
+    }
 }
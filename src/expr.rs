@@ -1,5 +1,7 @@
 use std::ops::RangeInclusive;
 
+use crate::calendar::{day_of_week, days_in_month, nearest_weekday};
+
 #[derive(Debug, Default, PartialEq, Eq, Clone)]
 pub struct CronExpr {
     pub second: Field,
@@ -17,35 +19,164 @@ impl CronExpr {
         self.second.check(&dt.second)
             && self.minute.check(&dt.minute)
             && self.hour.check(&dt.hour)
-            && self.day.check(&dt.day)
-            && self.month.check(&dt.month.into())
-            && self.day_of_week.check(&dt.day_of_week.into())
+            && self.day_matches(dt.year, dt.month, dt.day)
+            && self.month.check(&dt.month)
+    }
+
+    /// A day matches if the `day` field matches, OR the `day_of_week` field matches,
+    /// unless one of the two is `Any`, per standard cron semantics.
+    pub(crate) fn day_matches(&self, year: u16, month: u8, day: u8) -> bool {
+        match (&self.day, &self.day_of_week) {
+            (Field::Any, Field::Any) => true,
+            (Field::Any, _) => self.day_of_week.check_dow(year, month, day),
+            (_, Field::Any) => self.day.check_day(year, month, day),
+            _ => {
+                self.day.check_day(year, month, day) || self.day_of_week.check_dow(year, month, day)
+            }
+        }
+    }
+
+    /// Returns the field at `kind`.
+    pub fn field(&self, kind: FieldKind) -> &Field {
+        match kind {
+            FieldKind::Second => &self.second,
+            FieldKind::Minute => &self.minute,
+            FieldKind::Hour => &self.hour,
+            FieldKind::Day => &self.day,
+            FieldKind::Month => &self.month,
+            FieldKind::DayOfWeek => &self.day_of_week,
+        }
+    }
+
+    /// Returns a mutable reference to the field at `kind`.
+    pub fn field_mut(&mut self, kind: FieldKind) -> &mut Field {
+        match kind {
+            FieldKind::Second => &mut self.second,
+            FieldKind::Minute => &mut self.minute,
+            FieldKind::Hour => &mut self.hour,
+            FieldKind::Day => &mut self.day,
+            FieldKind::Month => &mut self.month,
+            FieldKind::DayOfWeek => &mut self.day_of_week,
+        }
+    }
+
+    /// Replaces the field at `kind`.
+    pub fn set_field(&mut self, kind: FieldKind, field: Field) {
+        *self.field_mut(kind) = field;
+    }
+
+    /// Iterates the six fields alongside their kind, in declaration order.
+    pub fn fields(&self) -> impl Iterator<Item = (FieldKind, &Field)> {
+        FieldKind::ALL.into_iter().map(|kind| (kind, self.field(kind)))
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+/// Identifies one of the six fields of a [`CronExpr`], for code that needs to work generically
+/// across all of them (parsing, validation, rendering) instead of matching on field names by
+/// hand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldKind {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Month,
+    DayOfWeek,
+}
+
+impl FieldKind {
+    /// All six kinds, in the order they appear in a cron expression.
+    const ALL: [FieldKind; 6] = [
+        FieldKind::Second,
+        FieldKind::Minute,
+        FieldKind::Hour,
+        FieldKind::Day,
+        FieldKind::Month,
+        FieldKind::DayOfWeek,
+    ];
+
+    /// The inclusive range of values this field can validly take.
+    pub fn bounds(self) -> RangeInclusive<u8> {
+        match self {
+            FieldKind::Second | FieldKind::Minute => 0..=59,
+            FieldKind::Hour => 0..=23,
+            FieldKind::Day => 1..=31,
+            FieldKind::Month => 1..=12,
+            FieldKind::DayOfWeek => 0..=6,
+        }
+    }
+
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            FieldKind::Second => "second",
+            FieldKind::Minute => "minute",
+            FieldKind::Hour => "hour",
+            FieldKind::Day => "day",
+            FieldKind::Month => "month",
+            FieldKind::DayOfWeek => "day_of_week",
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
 pub enum Field {
+    #[default]
     Any,
     Value(u8),
     Range(RangeInclusive<u8>),
     List(Vec<ListValue>),
     Step(StepValue, u8),
-}
-
-impl Default for Field {
-    fn default() -> Self {
-        Self::Any
-    }
+    /// `day_of_week` only: the `nth` occurrence of weekday `dow` in the month (Quartz `#`).
+    NthDayOfWeek { dow: u8, nth: u8 },
+    /// `day_of_week` only: the last occurrence of weekday `dow` in the month (Quartz `L`).
+    LastDayOfWeek(u8),
+    /// `day` only: the last day of the month (Quartz `L`).
+    LastDayOfMonth,
+    /// `day` only: the weekday nearest the given day of month (Quartz `W`).
+    NearestWeekday(u8),
 }
 
 impl Field {
-    fn check(&self, value: &u8) -> bool {
+    pub(crate) fn check(&self, value: &u8) -> bool {
         match self {
             Field::Any => true,
             Field::Value(v) => v == value,
             Field::Range(r) => r.contains(value),
             Field::List(l) => l.iter().any(|v| v.check(value)),
             Field::Step(r, s) => r.check(s, value),
+            Field::NthDayOfWeek { .. }
+            | Field::LastDayOfWeek(_)
+            | Field::LastDayOfMonth
+            | Field::NearestWeekday(_) => false,
+        }
+    }
+
+    /// Expands this field into the sorted list of values it allows within `domain`.
+    pub(crate) fn expand(&self, domain: RangeInclusive<u8>) -> Vec<u8> {
+        domain.filter(|v| self.check(v)).collect()
+    }
+
+    /// Checks this field as a `day` predicate, with the calendar context (`L`/`W` need the
+    /// month's length) needed to evaluate it.
+    pub(crate) fn check_day(&self, year: u16, month: u8, day: u8) -> bool {
+        match self {
+            Field::LastDayOfMonth => day == days_in_month(year, month),
+            Field::NearestWeekday(target) => day == nearest_weekday(year, month, *target),
+            Field::NthDayOfWeek { .. } | Field::LastDayOfWeek(_) => false,
+            _ => self.check(&day),
+        }
+    }
+
+    /// Checks this field as a `day_of_week` predicate, with the calendar context (`L`/`#`
+    /// need to know which occurrence of the weekday `day` is) needed to evaluate it.
+    pub(crate) fn check_dow(&self, year: u16, month: u8, day: u8) -> bool {
+        let dow = day_of_week(year, month, day);
+
+        match self {
+            Field::NthDayOfWeek { dow: d, nth } => *d == dow && (day - 1) / 7 + 1 == *nth,
+            Field::LastDayOfWeek(d) => *d == dow && day + 7 > days_in_month(year, month),
+            Field::LastDayOfMonth | Field::NearestWeekday(_) => false,
+            _ => self.check(&dow),
         }
     }
 }
@@ -74,20 +205,22 @@ pub enum StepValue {
 impl StepValue {
     fn check(&self, step: &u8, value: &u8) -> bool {
         match self {
-            StepValue::All => value % step == 0,
+            StepValue::All => value.is_multiple_of(*step),
             StepValue::Range(r) => {
-                if !r.contains(&value) {
+                if !r.contains(value) {
                     return false;
                 }
 
                 // start + step * n = value
-                (value - r.start()) % step == 0
+                (value - r.start()).is_multiple_of(*step)
             }
         }
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DateTime {
+    pub year: u16,
     pub second: u8,
     pub minute: u8,
     pub hour: u8,
@@ -98,27 +231,265 @@ pub struct DateTime {
 
 #[cfg(feature = "time_rs")]
 pub mod time_rs_conversion {
-    use ::time::OffsetDateTime;
+    use ::time::{OffsetDateTime, UtcOffset};
 
-    use crate::DateTime;
+    use crate::{CronExpr, DateTime, Occurrences};
 
     impl Into<DateTime> for OffsetDateTime {
         fn into(self) -> DateTime {
             DateTime {
+                year: self.year() as u16,
                 second: self.second(),
                 minute: self.minute(),
                 hour: self.hour(),
-                day: self.hour(),
+                day: self.day(),
                 month: self.month() as u8,
                 day_of_week: self.weekday().number_days_from_sunday(),
             }
         }
     }
+
+    impl CronExpr {
+        /// Timezone-aware counterpart to [`CronExpr::check_time`]: converts `dt` to `offset`
+        /// before breaking it into fields, so an expression written for a specific zone is
+        /// checked against the wall-clock time in that zone rather than `dt`'s own offset.
+        ///
+        /// This only applies a fixed UTC offset -- it knows nothing about DST or historical
+        /// offset changes. Callers who need real zone rules should resolve the correct
+        /// [`UtcOffset`] for the instant themselves (e.g. with `time-tz` or `chrono-tz`), or
+        /// implement [`ToLocal`] and use [`CronExpr::check_time_with`] instead.
+        pub fn check_time_in(&self, dt: OffsetDateTime, offset: UtcOffset) -> bool {
+            self.check_time(dt.to_offset(offset))
+        }
+
+        /// Timezone-aware counterpart to [`CronExpr::next_after`]. See [`CronExpr::check_time_in`]
+        /// for the caveats around fixed offsets versus real zone rules.
+        pub fn next_after_in(&self, dt: OffsetDateTime, offset: UtcOffset) -> Option<DateTime> {
+            self.next_after(dt.to_offset(offset))
+        }
+
+        /// Timezone-aware counterpart to [`CronExpr::prev_before`]. See [`CronExpr::check_time_in`]
+        /// for the caveats around fixed offsets versus real zone rules.
+        pub fn prev_before_in(&self, dt: OffsetDateTime, offset: UtcOffset) -> Option<DateTime> {
+            self.prev_before(dt.to_offset(offset))
+        }
+
+        /// Timezone-aware counterpart to [`CronExpr::occurrences_from`]. See
+        /// [`CronExpr::check_time_in`] for the caveats around fixed offsets versus real zone
+        /// rules.
+        pub fn occurrences_from_in(&self, dt: OffsetDateTime, offset: UtcOffset) -> Occurrences<'_> {
+            self.occurrences_from(dt.to_offset(offset))
+        }
+
+        /// Checks `dt` against this expression after converting it to local time via
+        /// `to_local` -- the escape hatch for callers who need real zone rules (DST,
+        /// historical offset changes) instead of the fixed-offset shift [`CronExpr::check_time_in`]
+        /// performs. A `time-tz` or `chrono-tz` zone lookup can be plugged in directly, since
+        /// [`ToLocal`] is implemented for any `Fn(OffsetDateTime) -> OffsetDateTime`.
+        pub fn check_time_with(&self, dt: OffsetDateTime, to_local: &impl ToLocal) -> bool {
+            self.check_time(to_local.to_local(dt))
+        }
+    }
+
+    /// A pluggable conversion from an instant to local wall-clock time, for callers who need
+    /// real timezone rules rather than the fixed-offset shift [`CronExpr::check_time_in`] and
+    /// its siblings perform. Implemented for any `Fn(OffsetDateTime) -> OffsetDateTime`, so a
+    /// `time-tz` or `chrono-tz` zone lookup can be used as-is.
+    pub trait ToLocal {
+        fn to_local(&self, dt: OffsetDateTime) -> OffsetDateTime;
+    }
+
+    impl<F: Fn(OffsetDateTime) -> OffsetDateTime> ToLocal for F {
+        fn to_local(&self, dt: OffsetDateTime) -> OffsetDateTime {
+            self(dt)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use ::time::{Date, Month, OffsetDateTime, Time, UtcOffset};
+
+        use crate::{CronExpr, Field};
+
+        fn instant() -> OffsetDateTime {
+            let date = Date::from_calendar_date(2024, Month::March, 1).unwrap();
+            let time = Time::from_hms(14, 0, 0).unwrap();
+            OffsetDateTime::new_utc(date, time)
+        }
+
+        #[test]
+        fn check_time_in_shifts_by_offset() {
+            let expr = CronExpr {
+                hour: Field::Value(14),
+                ..Default::default()
+            };
+
+            assert!(expr.check_time_in(instant(), UtcOffset::UTC));
+            assert!(!expr.check_time_in(instant(), UtcOffset::from_hms(2, 0, 0).unwrap()));
+        }
+
+        #[test]
+        fn check_time_with_delegates_to_the_closure() {
+            let expr = CronExpr {
+                hour: Field::Value(16),
+                ..Default::default()
+            };
+            let shift_two_hours =
+                |dt: OffsetDateTime| dt.to_offset(UtcOffset::from_hms(2, 0, 0).unwrap());
+
+            assert!(expr.check_time_with(instant(), &shift_two_hours));
+        }
+
+        #[test]
+        fn next_after_in_searches_from_the_shifted_wall_clock() {
+            let expr = CronExpr::parse("0 0 16 * * *").unwrap();
+
+            let utc_next = expr.next_after_in(instant(), UtcOffset::UTC).unwrap();
+            assert_eq!((2024, 3, 1), (utc_next.year, utc_next.month, utc_next.day));
+
+            // Shifting the instant to +02:00 makes the wall clock 16:00, so today's 16:00
+            // has already passed and the next match falls on the following day.
+            let plus_two_next = expr
+                .next_after_in(instant(), UtcOffset::from_hms(2, 0, 0).unwrap())
+                .unwrap();
+            assert_eq!((2024, 3, 2), (plus_two_next.year, plus_two_next.month, plus_two_next.day));
+        }
+
+        #[test]
+        fn prev_before_in_searches_from_the_shifted_wall_clock() {
+            let expr = CronExpr::parse("0 0 15 * * *").unwrap();
+
+            // At +00:00 the wall clock is 14:00, before today's 15:00, so the most recent
+            // match is the previous day's occurrence.
+            let utc_prev = expr.prev_before_in(instant(), UtcOffset::UTC).unwrap();
+            assert_eq!((2024, 2, 29), (utc_prev.year, utc_prev.month, utc_prev.day));
+
+            // At +02:00 the wall clock is 16:00, after today's 15:00, so today's occurrence
+            // is the most recent match.
+            let plus_two_prev = expr
+                .prev_before_in(instant(), UtcOffset::from_hms(2, 0, 0).unwrap())
+                .unwrap();
+            assert_eq!((2024, 3, 1), (plus_two_prev.year, plus_two_prev.month, plus_two_prev.day));
+        }
+
+        #[test]
+        fn occurrences_from_in_starts_from_the_shifted_wall_clock_and_continues_correctly() {
+            let expr = CronExpr::parse("0 0 0 * * *").unwrap();
+            let late = OffsetDateTime::new_utc(
+                Date::from_calendar_date(2024, Month::March, 1).unwrap(),
+                Time::from_hms(22, 0, 0).unwrap(),
+            );
+
+            let mut utc_occurrences = expr.occurrences_from_in(late, UtcOffset::UTC);
+            let first = utc_occurrences.next().unwrap();
+            assert_eq!((2024, 3, 2), (first.year, first.month, first.day));
+            let second = utc_occurrences.next().unwrap();
+            assert_eq!((2024, 3, 3), (second.year, second.month, second.day));
+
+            // Shifting to +03:00 rolls the wall clock past midnight into the next day, so the
+            // first occurrence found is a full day later than in the UTC case.
+            let mut plus_three_occurrences =
+                expr.occurrences_from_in(late, UtcOffset::from_hms(3, 0, 0).unwrap());
+            let first_shifted = plus_three_occurrences.next().unwrap();
+            assert_eq!(
+                (2024, 3, 3),
+                (first_shifted.year, first_shifted.month, first_shifted.day)
+            );
+        }
+    }
+}
+
+/// Mirrors [`time_rs_conversion`] for the `chrono` ecosystem.
+#[cfg(feature = "chrono")]
+pub mod chrono_conversion {
+    use ::chrono::{Datelike, TimeZone, Timelike};
+
+    use crate::DateTime;
+
+    impl<Tz: TimeZone> Into<DateTime> for ::chrono::DateTime<Tz> {
+        fn into(self) -> DateTime {
+            DateTime {
+                year: self.year() as u16,
+                second: self.second() as u8,
+                minute: self.minute() as u8,
+                hour: self.hour() as u8,
+                day: self.day() as u8,
+                month: self.month() as u8,
+                day_of_week: self.weekday().num_days_from_sunday() as u8,
+            }
+        }
+    }
+
+    impl Into<DateTime> for ::chrono::NaiveDateTime {
+        fn into(self) -> DateTime {
+            DateTime {
+                year: self.year() as u16,
+                second: self.second() as u8,
+                minute: self.minute() as u8,
+                hour: self.hour() as u8,
+                day: self.day() as u8,
+                month: self.month() as u8,
+                day_of_week: self.weekday().num_days_from_sunday() as u8,
+            }
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{tests::datetime, CronExpr, Field};
+    use crate::{tests::datetime, CronExpr, Field, FieldKind};
+
+    #[test]
+    fn field_accessors_round_trip() {
+        let mut expr = CronExpr {
+            hour: Field::Value(16),
+            ..Default::default()
+        };
+
+        assert_eq!(&Field::Value(16), expr.field(FieldKind::Hour));
+
+        expr.set_field(FieldKind::Hour, Field::Value(17));
+        assert_eq!(&Field::Value(17), expr.field(FieldKind::Hour));
+
+        *expr.field_mut(FieldKind::Minute) = Field::Value(30);
+        assert_eq!(&Field::Value(30), expr.field(FieldKind::Minute));
+    }
+
+    #[test]
+    fn fields_iterates_in_declaration_order() {
+        let expr = CronExpr::parse("1 2 3 4 5 6").unwrap();
+        let kinds: Vec<FieldKind> = expr.fields().map(|(kind, _)| kind).collect();
+
+        assert_eq!(
+            vec![
+                FieldKind::Second,
+                FieldKind::Minute,
+                FieldKind::Hour,
+                FieldKind::Day,
+                FieldKind::Month,
+                FieldKind::DayOfWeek,
+            ],
+            kinds
+        );
+        assert_eq!(
+            vec![
+                &Field::Value(1),
+                &Field::Value(2),
+                &Field::Value(3),
+                &Field::Value(4),
+                &Field::Value(5),
+                &Field::Value(6),
+            ],
+            expr.fields().map(|(_, f)| f).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn field_kind_bounds() {
+        assert_eq!(0..=59, FieldKind::Second.bounds());
+        assert_eq!(1..=31, FieldKind::Day.bounds());
+        assert_eq!(0..=6, FieldKind::DayOfWeek.bounds());
+    }
 
     #[test]
     fn check_time() {
@@ -141,4 +512,35 @@ mod tests {
     fn clone() {
         CronExpr::default().clone();
     }
+
+    #[test]
+    fn last_day_of_month() {
+        assert!(Field::LastDayOfMonth.check_day(2024, 2, 29));
+        assert!(!Field::LastDayOfMonth.check_day(2023, 2, 29));
+    }
+
+    #[test]
+    fn nth_day_of_week() {
+        // 2024-3-1 is a Friday; the second Friday of March 2024 is the 8th.
+        let second_friday = Field::NthDayOfWeek { dow: 5, nth: 2 };
+        assert!(!second_friday.check_dow(2024, 3, 1));
+        assert!(second_friday.check_dow(2024, 3, 8));
+    }
+
+    #[test]
+    fn nearest_weekday() {
+        // 2024-3-2 is a Saturday; the nearest weekday is the preceding Friday.
+        assert!(Field::NearestWeekday(2).check_day(2024, 3, 1));
+        assert!(!Field::NearestWeekday(2).check_day(2024, 3, 2));
+    }
+
+    #[test]
+    fn check_time_matches_day_and_day_of_week_with_or_semantics() {
+        // 2024-3-8 is a Friday, not the 13th: `day_matches` must OR the two fields so
+        // `check_time` agrees with what the scheduler in `schedule.rs` yields.
+        let expr = CronExpr::parse("0 0 0 13 * 5").unwrap();
+        assert!(expr.check_time(datetime!(2024-3-8 0:0:0 5)));
+        assert!(expr.check_time(datetime!(2024-3-13 0:0:0 3)));
+        assert!(!expr.check_time(datetime!(2024-3-2 0:0:0 6)));
+    }
 }
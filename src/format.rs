@@ -14,14 +14,22 @@ impl CronExpr {
             return Err(ParseError::Empty);
         }
 
+        if expr_str.starts_with('@') {
+            let expanded = resolve_nickname(expr_str).ok_or(ParseError::Field)?;
+            return Self::parse(expanded);
+        }
+
         let mut expr_fields = expr_str.split(|c: char| c.is_ascii_whitespace());
 
-        let second = parse_field(expr_fields.next().ok_or(ParseError::Incomplete)?)?;
-        let minute = parse_field(expr_fields.next().ok_or(ParseError::Incomplete)?)?;
-        let hour = parse_field(expr_fields.next().ok_or(ParseError::Incomplete)?)?;
-        let day = parse_field(expr_fields.next().ok_or(ParseError::Incomplete)?)?;
-        let month = parse_field(expr_fields.next().ok_or(ParseError::Incomplete)?)?;
-        let day_of_week = parse_field(expr_fields.next().ok_or(ParseError::Incomplete)?)?;
+        let second = parse_field(expr_fields.next().ok_or(ParseError::Incomplete)?, FieldKind::Second)?;
+        let minute = parse_field(expr_fields.next().ok_or(ParseError::Incomplete)?, FieldKind::Minute)?;
+        let hour = parse_field(expr_fields.next().ok_or(ParseError::Incomplete)?, FieldKind::Hour)?;
+        let day = parse_field(expr_fields.next().ok_or(ParseError::Incomplete)?, FieldKind::Day)?;
+        let month = parse_field(expr_fields.next().ok_or(ParseError::Incomplete)?, FieldKind::Month)?;
+        let day_of_week = parse_field(
+            expr_fields.next().ok_or(ParseError::Incomplete)?,
+            FieldKind::DayOfWeek,
+        )?;
 
         Ok(Self {
             second,
@@ -34,9 +42,9 @@ impl CronExpr {
     }
 }
 
-impl ToString for CronExpr {
-    fn to_string(&self) -> String {
-        format!(
+impl Display for CronExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let fields = format!(
             "{} {} {} {} {} {}",
             write_field(&self.second),
             write_field(&self.minute),
@@ -44,20 +52,45 @@ impl ToString for CronExpr {
             write_field(&self.day),
             write_field(&self.month),
             write_field(&self.day_of_week)
-        )
+        );
+
+        match NICKNAMES.iter().find(|(_, canonical)| *canonical == fields) {
+            Some((nickname, _)) => f.write_str(nickname),
+            None => f.write_str(&fields),
+        }
     }
 }
 
+/// Predefined schedule nicknames and the six-field expression (in this crate's
+/// seconds-leading layout) each one expands to.
+const NICKNAMES: [(&str, &str); 7] = [
+    ("@yearly", "0 0 0 1 1 *"),
+    ("@annually", "0 0 0 1 1 *"),
+    ("@monthly", "0 0 0 1 * *"),
+    ("@weekly", "0 0 0 * * 0"),
+    ("@daily", "0 0 0 * * *"),
+    ("@midnight", "0 0 0 * * *"),
+    ("@hourly", "0 0 * * * *"),
+];
+
+fn resolve_nickname(name: &str) -> Option<&'static str> {
+    NICKNAMES
+        .iter()
+        .find(|(nickname, _)| nickname.eq_ignore_ascii_case(name))
+        .map(|(_, expanded)| *expanded)
+}
+
 #[derive(Debug)]
 pub enum ParseError {
     Empty,
     Field,
     Incomplete,
+    OutOfRange { field: &'static str, value: u8 },
 }
 
 impl Display for ParseError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        <ParseError as Debug>::fmt(&self, f)
+        <ParseError as Debug>::fmt(self, f)
     }
 }
 
@@ -65,76 +98,270 @@ impl Error for ParseError {}
 
 pub type ParseResult<T> = core::result::Result<T, ParseError>;
 
-fn parse_field(field: &str) -> ParseResult<Field> {
+/// Which of the six fields is being parsed, so field-only syntax (the `day`/`day_of_week`
+/// extensions `L`, `W`, `#`) is only recognized where it's meaningful.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FieldKind {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Month,
+    DayOfWeek,
+}
+
+impl FieldKind {
+    /// The inclusive range of values this field can validly take.
+    fn domain(self) -> RangeInclusive<u8> {
+        match self {
+            FieldKind::Second | FieldKind::Minute => 0..=59,
+            FieldKind::Hour => 0..=23,
+            FieldKind::Day => 1..=31,
+            FieldKind::Month => 1..=12,
+            FieldKind::DayOfWeek => 0..=6,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            FieldKind::Second => "second",
+            FieldKind::Minute => "minute",
+            FieldKind::Hour => "hour",
+            FieldKind::Day => "day",
+            FieldKind::Month => "month",
+            FieldKind::DayOfWeek => "day_of_week",
+        }
+    }
+
+    /// Normalizes and validates a raw numeric value against this field's domain.
+    /// `day_of_week` additionally accepts `7` as an alias for Sunday (`0`).
+    fn validate(self, value: u8) -> ParseResult<u8> {
+        let value = if self == FieldKind::DayOfWeek && value == 7 {
+            0
+        } else {
+            value
+        };
+
+        if self.domain().contains(&value) {
+            Ok(value)
+        } else {
+            Err(ParseError::OutOfRange {
+                field: self.name(),
+                value,
+            })
+        }
+    }
+}
+
+fn parse_field(field: &str, kind: FieldKind) -> ParseResult<Field> {
     if field == "*" {
-        return Ok(Field::All);
+        return Ok(Field::Any);
     }
 
-    if let Ok(int_val) = field.parse::<u8>() {
-        return Ok(Field::Value(int_val));
+    if kind == FieldKind::Day {
+        if field == "L" {
+            return Ok(Field::LastDayOfMonth);
+        }
+
+        if let Some(nearest) = parse_nearest_weekday(field, kind)? {
+            return Ok(nearest);
+        }
     }
 
-    if let Some(step) = parse_step(field) {
+    if kind == FieldKind::DayOfWeek {
+        if let Some(last) = parse_last_day_of_week(field, kind)? {
+            return Ok(last);
+        }
+
+        if let Some(nth) = parse_nth_day_of_week(field, kind)? {
+            return Ok(nth);
+        }
+    }
+
+    if let Some(value) = parse_value(field, kind)? {
+        return Ok(Field::Value(value));
+    }
+
+    if let Some(step) = parse_step(field, kind)? {
         return Ok(step);
     }
 
-    if let Some(list) = parse_list(field) {
+    if let Some(list) = parse_list(field, kind)? {
         return Ok(Field::List(list));
     }
 
-    if let Some(range) = parse_range(field) {
+    if let Some(range) = parse_range(field, kind)? {
         return Ok(Field::Range(range));
     }
 
     Err(ParseError::Field)
 }
 
-fn parse_step(field: &str) -> Option<Field> {
-    let (range, step) = field.rsplit_once('/')?;
+const MONTH_ALIASES: [(&str, u8); 12] = [
+    ("JAN", 1),
+    ("FEB", 2),
+    ("MAR", 3),
+    ("APR", 4),
+    ("MAY", 5),
+    ("JUN", 6),
+    ("JUL", 7),
+    ("AUG", 8),
+    ("SEP", 9),
+    ("OCT", 10),
+    ("NOV", 11),
+    ("DEC", 12),
+];
+
+const DAY_OF_WEEK_ALIASES: [(&str, u8); 7] = [
+    ("SUN", 0),
+    ("MON", 1),
+    ("TUE", 2),
+    ("WED", 3),
+    ("THU", 4),
+    ("FRI", 5),
+    ("SAT", 6),
+];
+
+/// Resolves a three-letter month or weekday name (e.g. `JAN`, `fri`) to the numeric value it
+/// maps to in the relevant field's domain. Only meaningful for the `month` and `day_of_week`
+/// fields; other kinds never have aliases.
+fn resolve_alias(field: &str, kind: FieldKind) -> Option<u8> {
+    let table: &[(&str, u8)] = match kind {
+        FieldKind::Month => &MONTH_ALIASES,
+        FieldKind::DayOfWeek => &DAY_OF_WEEK_ALIASES,
+        _ => return None,
+    };
+
+    table
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(field))
+        .map(|(_, value)| *value)
+}
+
+/// Parses a single field value, accepting either a plain integer or (for `month` and
+/// `day_of_week`) a three-letter name alias. Returns `Ok(None)` when `field` isn't a plain
+/// value at all (so the caller can try another syntax), and `Err` when it is one but falls
+/// outside the field's domain.
+fn parse_value(field: &str, kind: FieldKind) -> ParseResult<Option<u8>> {
+    match field.parse::<u8>() {
+        Ok(raw) => Ok(Some(kind.validate(raw)?)),
+        Err(_) => Ok(resolve_alias(field, kind)),
+    }
+}
+
+fn parse_nearest_weekday(field: &str, kind: FieldKind) -> ParseResult<Option<Field>> {
+    let Some(raw) = field.strip_suffix('W') else {
+        return Ok(None);
+    };
+    let Ok(day) = raw.parse::<u8>() else {
+        return Ok(None);
+    };
+    Ok(Some(Field::NearestWeekday(kind.validate(day)?)))
+}
+
+fn parse_last_day_of_week(field: &str, kind: FieldKind) -> ParseResult<Option<Field>> {
+    let Some(raw) = field.strip_suffix('L') else {
+        return Ok(None);
+    };
+    let Ok(dow) = raw.parse::<u8>() else {
+        return Ok(None);
+    };
+    Ok(Some(Field::LastDayOfWeek(kind.validate(dow)?)))
+}
+
+/// Which occurrence of the weekday within the month: `#` only makes sense for 1st through
+/// 5th, since no weekday occurs a 6th time in a month.
+const NTH_DAY_OF_WEEK_DOMAIN: RangeInclusive<u8> = 1..=5;
+
+fn parse_nth_day_of_week(field: &str, kind: FieldKind) -> ParseResult<Option<Field>> {
+    let Some((dow, nth)) = field.split_once('#') else {
+        return Ok(None);
+    };
+    let Ok(dow) = dow.parse::<u8>() else {
+        return Ok(None);
+    };
+    let Ok(nth) = nth.parse::<u8>() else {
+        return Ok(None);
+    };
+
+    let dow = kind.validate(dow)?;
+    if !NTH_DAY_OF_WEEK_DOMAIN.contains(&nth) {
+        return Err(ParseError::OutOfRange { field: "nth", value: nth });
+    }
+
+    Ok(Some(Field::NthDayOfWeek { dow, nth }))
+}
+
+fn parse_step(field: &str, kind: FieldKind) -> ParseResult<Option<Field>> {
+    let Some((range, step)) = field.rsplit_once('/') else {
+        return Ok(None);
+    };
+
+    let Ok(s) = step.parse::<u8>() else {
+        return Ok(None);
+    };
 
-    let s: u8 = step.parse().ok()?;
+    if s == 0 {
+        return Err(ParseError::Field);
+    }
 
     let r = if range == "*" {
         StepValue::All
     } else {
-        StepValue::Range(parse_range(range)?)
+        match parse_range(range, kind)? {
+            Some(r) => StepValue::Range(r),
+            None => return Ok(None),
+        }
     };
 
-    Some(Field::Step(r, s))
+    Ok(Some(Field::Step(r, s)))
 }
 
-fn parse_range(field: &str) -> Option<RangeInclusive<u8>> {
-    let (start, end) = field.split_once('-')?;
+fn parse_range(field: &str, kind: FieldKind) -> ParseResult<Option<RangeInclusive<u8>>> {
+    let Some((start, end)) = field.split_once('-') else {
+        return Ok(None);
+    };
+
+    let Some(s) = parse_value(start, kind)? else {
+        return Ok(None);
+    };
+    let Some(e) = parse_value(end, kind)? else {
+        return Ok(None);
+    };
 
-    let s: u8 = start.parse().ok()?;
-    let e: u8 = end.parse().ok()?;
+    if s > e {
+        return Err(ParseError::Field);
+    }
 
-    Some(s..=e)
+    Ok(Some(s..=e))
 }
 
-fn parse_list(field: &str) -> Option<Vec<ListValue>> {
+fn parse_list(field: &str, kind: FieldKind) -> ParseResult<Option<Vec<ListValue>>> {
     let mut list = Vec::new();
 
     for item in field.split(',') {
-        let item_value = if let Ok(int_val) = item.parse::<u8>() {
-            ListValue::Value(int_val)
+        let item_value = if let Some(value) = parse_value(item, kind)? {
+            ListValue::Value(value)
         } else {
-            ListValue::Range(parse_range(item)?)
+            match parse_range(item, kind)? {
+                Some(r) => ListValue::Range(r),
+                None => return Ok(None),
+            }
         };
 
         list.push(item_value);
     }
 
     if list.len() < 2 {
-        return None;
+        return Ok(None);
     }
 
-    Some(list)
+    Ok(Some(list))
 }
 
 fn write_field(field: &Field) -> String {
     match field {
-        Field::All => String::from("*"),
+        Field::Any => String::from("*"),
         Field::Value(v) => v.to_string(),
         Field::Range(r) => write_range(r),
         Field::List(l) => l
@@ -143,6 +370,10 @@ fn write_field(field: &Field) -> String {
             .collect::<Vec<String>>()
             .join(","),
         Field::Step(sv, s) => write_step_value(sv, *s),
+        Field::NthDayOfWeek { dow, nth } => format!("{}#{}", dow, nth),
+        Field::LastDayOfWeek(dow) => format!("{}L", dow),
+        Field::LastDayOfMonth => String::from("L"),
+        Field::NearestWeekday(day) => format!("{}W", day),
     }
 }
 
@@ -186,14 +417,14 @@ mod serde {
             D: serde::Deserializer<'de>,
         {
             let expr = String::deserialize(deserializer)?;
-            Ok(CronExpr::parse(&expr).map_err(|e| serde::de::Error::custom(e.to_string()))?)
+            CronExpr::parse(&expr).map_err(|e| serde::de::Error::custom(e.to_string()))
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{CronExpr, Field, ListValue, StepValue};
+    use crate::{CronExpr, Field, ListValue, ParseError, StepValue};
 
     #[test]
     fn parse_expr() {
@@ -224,6 +455,120 @@ mod tests {
         assert_eq!("30 0-30/5 13-15,18 * * 1-5", expr);
     }
 
+    #[test]
+    fn parse_day_and_day_of_week_extensions() {
+        let expr = CronExpr::parse("0 0 0 15W * 5#2").unwrap();
+        assert_eq!(
+            CronExpr {
+                second: Field::Value(0),
+                minute: Field::Value(0),
+                hour: Field::Value(0),
+                day: Field::NearestWeekday(15),
+                day_of_week: Field::NthDayOfWeek { dow: 5, nth: 2 },
+                ..Default::default()
+            },
+            expr
+        );
+    }
+
+    #[test]
+    fn day_and_day_of_week_extensions_to_string() {
+        let expr = CronExpr {
+            day: Field::LastDayOfMonth,
+            day_of_week: Field::LastDayOfWeek(5),
+            ..Default::default()
+        }
+        .to_string();
+
+        assert_eq!("* * * L * 5L", expr);
+    }
+
+    #[test]
+    fn parse_month_and_day_of_week_aliases() {
+        let expr = CronExpr::parse("0 0 0 * jan,mar,DEC MON-FRI").unwrap();
+        assert_eq!(
+            CronExpr {
+                second: Field::Value(0),
+                minute: Field::Value(0),
+                hour: Field::Value(0),
+                month: Field::List(vec![
+                    ListValue::Value(1),
+                    ListValue::Value(3),
+                    ListValue::Value(12)
+                ]),
+                day_of_week: Field::Range(1..=5),
+                ..Default::default()
+            },
+            expr
+        );
+    }
+
+    #[test]
+    fn aliases_round_trip_numeric() {
+        let expr = CronExpr::parse("0 0 0 * JAN SUN").unwrap();
+        assert_eq!("0 0 0 * 1 0", expr.to_string());
+    }
+
+    #[test]
+    fn parse_nicknames() {
+        assert_eq!(
+            CronExpr::parse("0 0 0 1 1 *").unwrap(),
+            CronExpr::parse("@yearly").unwrap()
+        );
+        assert_eq!(
+            CronExpr::parse("0 0 0 * * *").unwrap(),
+            CronExpr::parse("@daily").unwrap()
+        );
+        assert!(CronExpr::parse("@nonsense").is_err());
+    }
+
+    #[test]
+    fn nicknames_round_trip() {
+        assert_eq!("@yearly", CronExpr::parse("@annually").unwrap().to_string());
+        assert_eq!("@hourly", CronExpr::parse("0 0 * * * *").unwrap().to_string());
+    }
+
+    #[test]
+    fn rejects_out_of_range_values() {
+        assert!(matches!(
+            CronExpr::parse("0 99 0 * * *"),
+            Err(ParseError::OutOfRange {
+                field: "minute",
+                value: 99
+            })
+        ));
+        assert!(matches!(
+            CronExpr::parse("0 0 0 * 13 *"),
+            Err(ParseError::OutOfRange {
+                field: "month",
+                value: 13
+            })
+        ));
+    }
+
+    #[test]
+    fn accepts_seven_as_sunday_in_day_of_week() {
+        assert_eq!(
+            CronExpr::parse("0 0 0 * * 0").unwrap(),
+            CronExpr::parse("0 0 0 * * 7").unwrap()
+        );
+    }
+
+    #[test]
+    fn rejects_misordered_range_and_zero_step() {
+        assert!(CronExpr::parse("0 30-10 0 * * *").is_err());
+        assert!(CronExpr::parse("0 */0 0 * * *").is_err());
+    }
+
+    #[test]
+    fn rejects_out_of_range_quartz_extensions() {
+        assert!(CronExpr::parse("0 0 0 * * 9#2").is_err());
+        assert!(CronExpr::parse("0 0 0 * * 5#9").is_err());
+        assert!(CronExpr::parse("0 0 0 * * 9L").is_err());
+        assert!(CronExpr::parse("0 0 0 32W * *").is_err());
+        assert!(CronExpr::parse("0 0 0 0W * *").is_err());
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde() {
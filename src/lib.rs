@@ -1,14 +1,20 @@
+mod calendar;
+mod describe;
 mod expr;
 mod format;
+mod schedule;
 
+pub use describe::*;
 pub use expr::*;
 pub use format::*;
+pub use schedule::*;
 
 #[cfg(test)]
 pub(crate) mod tests {
     macro_rules! datetime {
         ($yy:literal-$mm:literal-$dd:literal $hh:literal:$mi:literal:$ss:literal $w:literal) => {
             crate::DateTime {
+                year: $yy,
                 second: $ss,
                 minute: $mi,
                 hour: $hh,
@@ -0,0 +1,435 @@
+//! Turning a [`CronExpr`] match predicate into occurrence search, the way an rrule-style
+//! scheduler would: expand each field into its allowed values, then walk a "field odometer"
+//! from the most significant field down until every field lines up.
+
+use crate::calendar::{day_of_week, days_in_month};
+use crate::{CronExpr, DateTime};
+
+/// How many years forward (or backward) to search before giving up on an expression that
+/// can never match (e.g. `0 0 0 30 2 *`, a February 30th that never occurs). Must be wide
+/// enough to span the largest Feb-29 gap: century years not divisible by 400 (e.g. 2100)
+/// aren't leap years, stretching the gap between leap days to 8 years.
+const SEARCH_HORIZON_YEARS: u16 = 10;
+
+impl CronExpr {
+    /// Returns the next instant strictly after `dt` that matches this expression.
+    pub fn next_after<T: Into<DateTime>>(&self, dt: T) -> Option<DateTime> {
+        let mut dt = dt.into();
+        add_one_second(&mut dt);
+        self.search_forward(dt)
+    }
+
+    /// Returns the most recent instant strictly before `dt` that matches this expression.
+    pub fn prev_before<T: Into<DateTime>>(&self, dt: T) -> Option<DateTime> {
+        let mut dt = dt.into();
+        sub_one_second(&mut dt);
+        self.search_backward(dt)
+    }
+
+    /// Iterates successive matching instants starting at (and including, if it matches)
+    /// `dt`.
+    pub fn occurrences_from<T: Into<DateTime>>(&self, dt: T) -> Occurrences<'_> {
+        Occurrences {
+            expr: self,
+            next: self.search_forward(dt.into()),
+        }
+    }
+
+    fn search_forward(&self, mut dt: DateTime) -> Option<DateTime> {
+        let seconds = self.second.expand(0..=59);
+        let minutes = self.minute.expand(0..=59);
+        let hours = self.hour.expand(0..=23);
+        let months = self.month.expand(1..=12);
+
+        if seconds.is_empty() || minutes.is_empty() || hours.is_empty() || months.is_empty() {
+            return None;
+        }
+
+        let horizon = dt.year + SEARCH_HORIZON_YEARS;
+
+        loop {
+            if dt.year > horizon {
+                return None;
+            }
+
+            match next_in(&months, dt.month) {
+                None => {
+                    dt.year += 1;
+                    dt.month = 1;
+                    dt.day = 1;
+                    dt.hour = 0;
+                    dt.minute = 0;
+                    dt.second = 0;
+                    continue;
+                }
+                Some(month) if month != dt.month => {
+                    dt.month = month;
+                    dt.day = 1;
+                    dt.hour = 0;
+                    dt.minute = 0;
+                    dt.second = 0;
+                    continue;
+                }
+                _ => {}
+            }
+
+            match self.next_day_in_month(dt.year, dt.month, dt.day) {
+                None => {
+                    add_one_month(&mut dt);
+                    dt.day = 1;
+                    dt.hour = 0;
+                    dt.minute = 0;
+                    dt.second = 0;
+                    continue;
+                }
+                Some(day) if day != dt.day => {
+                    dt.day = day;
+                    dt.hour = 0;
+                    dt.minute = 0;
+                    dt.second = 0;
+                    continue;
+                }
+                _ => {}
+            }
+
+            match next_in(&hours, dt.hour) {
+                None => {
+                    add_one_day(&mut dt);
+                    dt.hour = 0;
+                    dt.minute = 0;
+                    dt.second = 0;
+                    continue;
+                }
+                Some(hour) if hour != dt.hour => {
+                    dt.hour = hour;
+                    dt.minute = 0;
+                    dt.second = 0;
+                    continue;
+                }
+                _ => {}
+            }
+
+            match next_in(&minutes, dt.minute) {
+                None => {
+                    add_one_hour(&mut dt);
+                    dt.minute = 0;
+                    dt.second = 0;
+                    continue;
+                }
+                Some(minute) if minute != dt.minute => {
+                    dt.minute = minute;
+                    dt.second = 0;
+                    continue;
+                }
+                _ => {}
+            }
+
+            match next_in(&seconds, dt.second) {
+                None => {
+                    add_one_minute(&mut dt);
+                    dt.second = 0;
+                    continue;
+                }
+                Some(second) if second != dt.second => {
+                    dt.second = second;
+                    continue;
+                }
+                _ => {}
+            }
+
+            dt.day_of_week = day_of_week(dt.year, dt.month, dt.day);
+            return Some(dt);
+        }
+    }
+
+    fn search_backward(&self, mut dt: DateTime) -> Option<DateTime> {
+        let seconds = self.second.expand(0..=59);
+        let minutes = self.minute.expand(0..=59);
+        let hours = self.hour.expand(0..=23);
+        let months = self.month.expand(1..=12);
+
+        if seconds.is_empty() || minutes.is_empty() || hours.is_empty() || months.is_empty() {
+            return None;
+        }
+
+        let floor = dt.year.saturating_sub(SEARCH_HORIZON_YEARS);
+
+        loop {
+            if dt.year < floor {
+                return None;
+            }
+
+            match prev_in(&months, dt.month) {
+                None => {
+                    if dt.year == 0 {
+                        return None;
+                    }
+                    dt.year -= 1;
+                    dt.month = 12;
+                    dt.day = 31;
+                    dt.hour = 23;
+                    dt.minute = 59;
+                    dt.second = 59;
+                    continue;
+                }
+                Some(month) if month != dt.month => {
+                    dt.month = month;
+                    dt.day = 31;
+                    dt.hour = 23;
+                    dt.minute = 59;
+                    dt.second = 59;
+                    continue;
+                }
+                _ => {}
+            }
+
+            match self.prev_day_in_month(dt.year, dt.month, dt.day) {
+                None => {
+                    sub_one_month(&mut dt);
+                    dt.day = 31;
+                    dt.hour = 23;
+                    dt.minute = 59;
+                    dt.second = 59;
+                    continue;
+                }
+                Some(day) if day != dt.day => {
+                    dt.day = day;
+                    dt.hour = 23;
+                    dt.minute = 59;
+                    dt.second = 59;
+                    continue;
+                }
+                _ => {}
+            }
+
+            match prev_in(&hours, dt.hour) {
+                None => {
+                    sub_one_day(&mut dt);
+                    dt.hour = 23;
+                    dt.minute = 59;
+                    dt.second = 59;
+                    continue;
+                }
+                Some(hour) if hour != dt.hour => {
+                    dt.hour = hour;
+                    dt.minute = 59;
+                    dt.second = 59;
+                    continue;
+                }
+                _ => {}
+            }
+
+            match prev_in(&minutes, dt.minute) {
+                None => {
+                    sub_one_hour(&mut dt);
+                    dt.minute = 59;
+                    dt.second = 59;
+                    continue;
+                }
+                Some(minute) if minute != dt.minute => {
+                    dt.minute = minute;
+                    dt.second = 59;
+                    continue;
+                }
+                _ => {}
+            }
+
+            match prev_in(&seconds, dt.second) {
+                None => {
+                    sub_one_minute(&mut dt);
+                    dt.second = 59;
+                    continue;
+                }
+                Some(second) if second != dt.second => {
+                    dt.second = second;
+                    continue;
+                }
+                _ => {}
+            }
+
+            dt.day_of_week = day_of_week(dt.year, dt.month, dt.day);
+            return Some(dt);
+        }
+    }
+
+    fn next_day_in_month(&self, year: u16, month: u8, start_day: u8) -> Option<u8> {
+        let last = days_in_month(year, month);
+        if start_day > last {
+            return None;
+        }
+        (start_day..=last).find(|&day| self.day_matches(year, month, day))
+    }
+
+    fn prev_day_in_month(&self, year: u16, month: u8, start_day: u8) -> Option<u8> {
+        let last = days_in_month(year, month).min(start_day);
+        if last == 0 {
+            return None;
+        }
+        (1..=last).rev().find(|&day| self.day_matches(year, month, day))
+    }
+}
+
+/// Successive matching instants produced by [`CronExpr::occurrences_from`].
+pub struct Occurrences<'a> {
+    expr: &'a CronExpr,
+    next: Option<DateTime>,
+}
+
+impl<'a> Iterator for Occurrences<'a> {
+    type Item = DateTime;
+
+    fn next(&mut self) -> Option<DateTime> {
+        let current = self.next?;
+        self.next = self.expr.next_after(current);
+        Some(current)
+    }
+}
+
+fn next_in(values: &[u8], from: u8) -> Option<u8> {
+    values.iter().copied().find(|v| *v >= from)
+}
+
+fn prev_in(values: &[u8], from: u8) -> Option<u8> {
+    values.iter().copied().rev().find(|v| *v <= from)
+}
+
+fn add_one_second(dt: &mut DateTime) {
+    dt.second += 1;
+    if dt.second >= 60 {
+        dt.second = 0;
+        add_one_minute(dt);
+    }
+}
+
+fn add_one_minute(dt: &mut DateTime) {
+    dt.minute += 1;
+    if dt.minute >= 60 {
+        dt.minute = 0;
+        add_one_hour(dt);
+    }
+}
+
+fn add_one_hour(dt: &mut DateTime) {
+    dt.hour += 1;
+    if dt.hour >= 24 {
+        dt.hour = 0;
+        add_one_day(dt);
+    }
+}
+
+fn add_one_day(dt: &mut DateTime) {
+    dt.day += 1;
+    if dt.day > days_in_month(dt.year, dt.month) {
+        dt.day = 1;
+        add_one_month(dt);
+    }
+}
+
+fn add_one_month(dt: &mut DateTime) {
+    dt.month += 1;
+    if dt.month > 12 {
+        dt.month = 1;
+        dt.year += 1;
+    }
+}
+
+fn sub_one_second(dt: &mut DateTime) {
+    if dt.second == 0 {
+        dt.second = 59;
+        sub_one_minute(dt);
+    } else {
+        dt.second -= 1;
+    }
+}
+
+fn sub_one_minute(dt: &mut DateTime) {
+    if dt.minute == 0 {
+        dt.minute = 59;
+        sub_one_hour(dt);
+    } else {
+        dt.minute -= 1;
+    }
+}
+
+fn sub_one_hour(dt: &mut DateTime) {
+    if dt.hour == 0 {
+        dt.hour = 23;
+        sub_one_day(dt);
+    } else {
+        dt.hour -= 1;
+    }
+}
+
+fn sub_one_day(dt: &mut DateTime) {
+    if dt.day == 1 {
+        sub_one_month(dt);
+        dt.day = days_in_month(dt.year, dt.month);
+    } else {
+        dt.day -= 1;
+    }
+}
+
+fn sub_one_month(dt: &mut DateTime) {
+    if dt.month == 1 {
+        dt.month = 12;
+        dt.year -= 1;
+    } else {
+        dt.month -= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tests::datetime, CronExpr};
+
+    #[test]
+    fn next_after_same_day() {
+        let expr = CronExpr::parse("0 30 9 * * *").unwrap();
+        let next = expr.next_after(datetime!(2024-3-1 8:0:0 5)).unwrap();
+        assert_eq!(next, datetime!(2024-3-1 9:30:0 5));
+    }
+
+    #[test]
+    fn next_after_rolls_into_next_month() {
+        let expr = CronExpr::parse("0 0 0 1 * *").unwrap();
+        let next = expr.next_after(datetime!(2024-3-1 0:0:0 5)).unwrap();
+        assert_eq!(next, datetime!(2024-4-1 0:0:0 1));
+    }
+
+    #[test]
+    fn next_after_respects_leap_years() {
+        let expr = CronExpr::parse("0 0 0 29 2 *").unwrap();
+        let next = expr.next_after(datetime!(2024-2-29 0:0:0 4)).unwrap();
+        assert_eq!(next, datetime!(2028-2-29 0:0:0 2));
+    }
+
+    #[test]
+    fn next_after_returns_none_for_impossible_expression() {
+        let expr = CronExpr::parse("0 0 0 30 2 *").unwrap();
+        assert_eq!(None, expr.next_after(datetime!(2024-1-1 0:0:0 1)));
+    }
+
+    #[test]
+    fn prev_before_is_the_mirror_of_next_after() {
+        let expr = CronExpr::parse("0 0 12 * * *").unwrap();
+        let prev = expr.prev_before(datetime!(2024-3-2 0:0:0 6)).unwrap();
+        assert_eq!(prev, datetime!(2024-3-1 12:0:0 5));
+    }
+
+    #[test]
+    fn next_after_spans_the_2100_leap_gap() {
+        // 2100 isn't a leap year, so the gap between Feb 29ths straddling it is 8 years.
+        let expr = CronExpr::parse("0 0 0 29 2 *").unwrap();
+        let next = expr.next_after(datetime!(2097-3-1 0:0:0 1)).unwrap();
+        assert_eq!(next, datetime!(2104-2-29 0:0:0 5));
+    }
+
+    #[test]
+    fn occurrences_from_includes_matching_start() {
+        let expr = CronExpr::parse("0 0 0 1 * *").unwrap();
+        let mut occurrences = expr.occurrences_from(datetime!(2024-3-1 0:0:0 5));
+
+        assert_eq!(Some(datetime!(2024-3-1 0:0:0 5)), occurrences.next());
+        assert_eq!(Some(datetime!(2024-4-1 0:0:0 1)), occurrences.next());
+    }
+}